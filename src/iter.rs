@@ -2,35 +2,84 @@ use core::fmt::Debug;
 
 use alloc::boxed::Box;
 
+use crate::{Failure, Nothing, Thing};
+
 /// Repersents an iterator that can return None with the expectation that it
 /// will return Some in the future.
 ///
-/// This is repersented through results enum. When using this trait there is an
-/// expectation that the none variant is not signifigent.
-/// repersents nothing and [`Eof`] repersent the true end the stream.
+/// This is repersented through the [`Fallible`](crate::Fallible) enum. When
+/// using this trait there is an expectation that the [`Nothing`] variant is
+/// not signifigent and only [`Failure`] repersents the true end the stream —
+/// that's the contract [`seek_next`](Self::seek_next) and the `fuse_err`/
+/// `with_patience` family follow, polling straight through every [`Nothing`]
+/// (bounded or not) since a well behaved source may emit one mid-stream
+/// without being done.
+///
+/// The bulk-draining methods ([`count`](Self::count), [`last`](Self::last),
+/// [`nth`](Self::nth), [`fold`](Self::fold), [`for_each`](Self::for_each))
+/// are the one exception: they treat [`Nothing`] as a clean, valid end of
+/// the sequence, mirroring the ordinary `while let Some(x) = iter.next() {}`
+/// idiom most callers reach for them expecting. Reach for
+/// [`seek_next`](Self::seek_next)/[`fuse_err`](Self::fuse_err) instead when
+/// your source uses [`Nothing`] for transient gaps that should be polled
+/// through rather than treated as the end.
 ///
 /// The goal is the make iterators that dont have to return and enum with some
 /// garbage none type.
 pub trait FallibleIterator {
+    /// The type of a real, yielded value.
     type SomeItem;
+    /// The type of a real error.
     type Error;
 
     /// Required implementation
-    fn some_next(&mut self) -> Result<Option<Self::SomeItem>, Self::Error>;
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error>;
 
     /// Polls this iterator until it returns a value. Returns Nothing when if
     /// finds an errors. This will continue polling the iterator even if it
-    /// returns None.
+    /// returns None — unlike [`count`](Self::count) and the other
+    /// bulk-draining methods below, which stop cleanly the moment a
+    /// [`Nothing`] comes back (see the trait doc for why).
     fn seek_next(&mut self) -> Option<Self::SomeItem> {
         loop {
             match self.some_next() {
-                Some(Ok(t)) => return Some(t),
-                Some(Err(_)) => return None,
-                None => {}
+                Thing(t) => return Some(t),
+                Failure(_) => return None,
+                Nothing => {}
             }
         }
     }
 
+    /// Like [`seek_next`](Self::seek_next) but bails out with [`None`] after
+    /// `max_empty` consecutive [`Nothing`]s instead of polling forever. This
+    /// is the safe version to reach for when the source might sit there
+    /// repeating [`Nothing`] without ever producing a real value.
+    fn seek_next_within(&mut self, max_empty: usize) -> Option<Self::SomeItem> {
+        let mut remaining = max_empty;
+        loop {
+            if remaining == 0 {
+                return None;
+            }
+            match self.some_next() {
+                Thing(t) => return Some(t),
+                Failure(_) => return None,
+                Nothing => remaining -= 1,
+            }
+        }
+    }
+
+    /// Drains this iterator into a plain [`Iterator`] yielding [`Self::SomeItem`],
+    /// skipping every [`Nothing`] and stopping at the first [`Failure`]. An
+    /// alias for [`fuse_err`](Self::fuse_err) that reads better at call sites
+    /// like `for item in source.as_iter() { ... }`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_iter(self) -> FuseIterAlways<Self>
+    where
+        Self: Sized + 'static,
+    {
+        self.fuse_err()
+    }
+
     /// Converts this Iterator into a [`Iterator`] that will skip none values
     /// and end when any error is returned.
     fn fuse_err(self) -> FuseIterAlways<Self>
@@ -65,6 +114,143 @@ pub trait FallibleIterator {
             cond: Box::new(f),
         }
     }
+
+    /// Drains the iterator, counting the items it yields. Unlike
+    /// [`seek_next`](Self::seek_next), a [`Nothing`] here marks a clean end
+    /// of the stream rather than something to poll past; a [`Failure`] still
+    /// short-circuits the count.
+    fn count(mut self) -> Result<usize, Self::Error>
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+        loop {
+            match self.some_next() {
+                Thing(_) => count += 1,
+                Nothing => return Ok(count),
+                Failure(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Drains the iterator, holding onto the last item it yields. A
+    /// [`Nothing`] marks a clean end of the stream.
+    fn last(mut self) -> Result<Option<Self::SomeItem>, Self::Error>
+    where
+        Self: Sized,
+    {
+        let mut last = None;
+        loop {
+            match self.some_next() {
+                Thing(t) => last = Some(t),
+                Nothing => return Ok(last),
+                Failure(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Skips `n` items then returns the one after, threading any error that
+    /// surfaces along the way. A [`Nothing`] marks a clean end of the
+    /// stream, returning [`None`].
+    fn nth(&mut self, mut n: usize) -> Result<Option<Self::SomeItem>, Self::Error> {
+        loop {
+            match self.some_next() {
+                Thing(item) => {
+                    if n == 0 {
+                        return Ok(Some(item));
+                    }
+                    n -= 1;
+                }
+                Nothing => return Ok(None),
+                Failure(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Folds every item into an accumulator, stopping early the moment
+    /// `some_next` returns a [`Failure`]. A [`Nothing`] marks a clean end of
+    /// the stream, returning the accumulator as it stands.
+    fn fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::SomeItem) -> B,
+    {
+        let mut acc = init;
+        loop {
+            match self.some_next() {
+                Thing(item) => acc = f(acc, item),
+                Nothing => return Ok(acc),
+                Failure(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Calls `f` on every item, stopping early the moment `some_next` returns
+    /// a [`Failure`]. A [`Nothing`] marks a clean end of the stream.
+    fn for_each<F>(mut self, mut f: F) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(Self::SomeItem),
+    {
+        loop {
+            match self.some_next() {
+                Thing(item) => f(item),
+                Nothing => return Ok(()),
+                Failure(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Lazily maps every yielded item through `f`.
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized + 'static,
+        F: FnMut(Self::SomeItem) -> B,
+    {
+        Map { inner: self, f }
+    }
+
+    /// Lazily skips items for which `f` returns false.
+    fn filter<F>(self, f: F) -> Filter<Self, F>
+    where
+        Self: Sized + 'static,
+        F: FnMut(&Self::SomeItem) -> bool,
+    {
+        Filter { inner: self, f }
+    }
+
+    /// Lazily maps every item through `f`, skipping the ones that come back
+    /// [`None`].
+    fn filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized + 'static,
+        F: FnMut(Self::SomeItem) -> Option<B>,
+    {
+        FilterMap { inner: self, f }
+    }
+
+    /// Lazily stops yielding once `n` items have come through.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized + 'static,
+    {
+        Take {
+            inner: self,
+            remaining: n,
+        }
+    }
+
+    /// Wraps this iterator so a single lookahead result can be peeked at
+    /// without consuming it.
+    fn peekable(self) -> FalliblePeekable<Self>
+    where
+        Self: Sized,
+    {
+        FalliblePeekable {
+            inner: self,
+            peeked: None,
+        }
+    }
 }
 
 /// A wrapper type around [`FallibleIteratorExt`] that skips the None values
@@ -108,9 +294,9 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             match self.inner.some_next() {
-                Some(Ok(t)) => return Some(t),
-                Some(Err(e)) if e == self.variant => return None,
-                Some(Err(_)) | None => {}
+                Thing(t) => return Some(t),
+                Failure(e) if e == self.variant => return None,
+                Failure(_) | Nothing => {}
             }
         }
     }
@@ -125,6 +311,17 @@ where
     cond: Box<dyn Fn(I::Error) -> bool>,
 }
 
+impl<I> Debug for FuseIterClosure<I>
+where
+    I: FallibleIterator + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FuseIterClosure")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I> Iterator for FuseIterClosure<I>
 where
     I: FallibleIterator,
@@ -134,22 +331,406 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             match self.inner.some_next() {
-                Some(Ok(t)) => return Some(t),
-                Some(Err(e)) => {
+                Thing(t) => return Some(t),
+                Failure(e) => {
                     if !(self.cond)(e) {
                         return None;
                     }
                 }
-                None => {}
+                Nothing => {}
+            }
+        }
+    }
+}
+
+/// Houses [`Step`] and [`FuseStep`] in a module nobody outside this file can
+/// name, so a `pub` trait ([`WithPatience`](super::WithPatience)) can
+/// require [`FuseStep`] as a supertrait without leaking a bound downstream
+/// crates could actually implement against.
+mod sealed {
+    /// The outcome of a single, un-retried poll of a fuse adapter's inner
+    /// [`FallibleIterator`](super::FallibleIterator).
+    #[derive(Debug)]
+    pub enum Step<T> {
+        /// A real item came back.
+        Yielded(T),
+        /// The poll was insignificant, try again.
+        Skipped,
+        /// The adapter's stop condition was hit.
+        Stopped,
+    }
+
+    /// Sealed helper that lets [`WithPatience`](super::WithPatience) wrap
+    /// any of the three fuse adapters without re-running their own unbounded
+    /// retry loop.
+    pub trait FuseStep: Iterator {
+        /// Polls the underlying [`FallibleIterator`](super::FallibleIterator)
+        /// exactly once.
+        fn step(&mut self) -> Step<Self::Item>;
+    }
+}
+
+use sealed::{FuseStep, Step};
+
+impl<I> FuseStep for FuseIterAlways<I>
+where
+    I: FallibleIterator,
+{
+    fn step(&mut self) -> Step<Self::Item> {
+        match self.inner.some_next() {
+            Thing(t) => Step::Yielded(t),
+            Nothing => Step::Skipped,
+            Failure(_) => Step::Stopped,
+        }
+    }
+}
+
+impl<I> FuseStep for FuseIterVariant<I>
+where
+    I: FallibleIterator,
+    I::Error: PartialEq,
+{
+    fn step(&mut self) -> Step<Self::Item> {
+        match self.inner.some_next() {
+            Thing(t) => Step::Yielded(t),
+            Failure(e) if e == self.variant => Step::Stopped,
+            Failure(_) | Nothing => Step::Skipped,
+        }
+    }
+}
+
+impl<I> FuseStep for FuseIterClosure<I>
+where
+    I: FallibleIterator,
+{
+    fn step(&mut self) -> Step<Self::Item> {
+        match self.inner.some_next() {
+            Thing(t) => Step::Yielded(t),
+            Failure(e) => {
+                if (self.cond)(e) {
+                    Step::Skipped
+                } else {
+                    Step::Stopped
+                }
+            }
+            Nothing => Step::Skipped,
+        }
+    }
+}
+
+/// Wraps a fuse adapter with a patience budget. Builds via
+/// [`WithPatience::with_patience`].
+#[derive(Debug)]
+pub struct Patience<I> {
+    inner: I,
+    budget: usize,
+    remaining: usize,
+}
+
+impl<I> Iterator for Patience<I>
+where
+    I: FuseStep,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining == 0 {
+                return None;
+            }
+            match self.inner.step() {
+                Step::Yielded(t) => {
+                    self.remaining = self.budget;
+                    return Some(t);
+                }
+                Step::Skipped => self.remaining -= 1,
+                Step::Stopped => return None,
+            }
+        }
+    }
+}
+
+/// Adds [`with_patience`](Self::with_patience) to any fuse adapter, bounding
+/// how many consecutive non-yielding polls it will sit through before
+/// [`Iterator::next`] gives up and returns [`None`]. The budget resets every
+/// time a real item is produced.
+pub trait WithPatience: FuseStep + Sized {
+    /// Wraps this fuse adapter with a patience `budget`.
+    fn with_patience(self, budget: usize) -> Patience<Self> {
+        Patience {
+            inner: self,
+            budget,
+            remaining: budget,
+        }
+    }
+}
+
+impl<I> WithPatience for I where I: FuseStep {}
+
+/// A lazy adapter around [`FallibleIterator::map`].
+#[derive(Debug)]
+pub struct Map<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F, B> FallibleIterator for Map<I, F>
+where
+    I: FallibleIterator,
+    F: FnMut(I::SomeItem) -> B,
+{
+    type SomeItem = B;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        match self.inner.some_next() {
+            Thing(t) => Thing((self.f)(t)),
+            Nothing => Nothing,
+            Failure(e) => Failure(e),
+        }
+    }
+}
+
+/// A lazy adapter around [`FallibleIterator::filter`].
+#[derive(Debug)]
+pub struct Filter<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F> FallibleIterator for Filter<I, F>
+where
+    I: FallibleIterator,
+    F: FnMut(&I::SomeItem) -> bool,
+{
+    type SomeItem = I::SomeItem;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        loop {
+            match self.inner.some_next() {
+                Thing(item) if (self.f)(&item) => return Thing(item),
+                Thing(_) => {}
+                Nothing => return Nothing,
+                Failure(e) => return Failure(e),
+            }
+        }
+    }
+}
+
+/// A lazy adapter around [`FallibleIterator::filter_map`].
+#[derive(Debug)]
+pub struct FilterMap<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F, B> FallibleIterator for FilterMap<I, F>
+where
+    I: FallibleIterator,
+    F: FnMut(I::SomeItem) -> Option<B>,
+{
+    type SomeItem = B;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        loop {
+            match self.inner.some_next() {
+                Thing(item) => {
+                    if let Some(out) = (self.f)(item) {
+                        return Thing(out);
+                    }
+                }
+                Nothing => return Nothing,
+                Failure(e) => return Failure(e),
+            }
+        }
+    }
+}
+
+/// A lazy adapter around [`FallibleIterator::take`].
+#[derive(Debug)]
+pub struct Take<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I> FallibleIterator for Take<I>
+where
+    I: FallibleIterator,
+{
+    type SomeItem = I::SomeItem;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        if self.remaining == 0 {
+            return Nothing;
+        }
+        match self.inner.some_next() {
+            Thing(t) => {
+                self.remaining -= 1;
+                Thing(t)
+            }
+            other => other,
+        }
+    }
+}
+
+/// A lazy adapter around [`FallibleIterator::peekable`].
+#[derive(Debug)]
+pub struct FalliblePeekable<I>
+where
+    I: FallibleIterator,
+{
+    inner: I,
+    peeked: Option<crate::Fallible<I::SomeItem, I::Error>>,
+}
+
+impl<I> FalliblePeekable<I>
+where
+    I: FallibleIterator,
+{
+    /// Peeks at the next item without consuming it. [`Nothing`] is skipped
+    /// over same as everywhere else, but a buffered [`Failure`] stays
+    /// buffered and keeps being handed back here until it's actually
+    /// consumed through [`FallibleIterator::some_next`]. This polls forever
+    /// through consecutive [`Nothing`]s, same as
+    /// [`seek_next`](FallibleIterator::seek_next); reach for
+    /// [`peek_within`](Self::peek_within) if the source might sit there
+    /// repeating [`Nothing`] without ever producing a real value.
+    pub fn peek(&mut self) -> Result<Option<&I::SomeItem>, I::Error>
+    where
+        I::Error: Clone,
+    {
+        if self.peeked.is_none() {
+            self.peeked = Some(loop {
+                match self.inner.some_next() {
+                    Nothing => {}
+                    result => break result,
+                }
+            });
+        }
+        match self.peeked.as_ref().expect("just filled above") {
+            Thing(t) => Ok(Some(t)),
+            Nothing => unreachable!("a buffered peek is never Nothing"),
+            Failure(e) => Err(e.clone()),
+        }
+    }
+
+    /// Like [`peek`](Self::peek) but gives up with `Ok(None)` after
+    /// `max_empty` consecutive [`Nothing`]s instead of polling forever,
+    /// mirroring [`seek_next_within`](FallibleIterator::seek_next_within).
+    /// A result that arrives within budget is buffered exactly like
+    /// [`peek`](Self::peek) buffers it; nothing is cached when the budget
+    /// runs out, so the next call starts a fresh attempt.
+    pub fn peek_within(&mut self, max_empty: usize) -> Result<Option<&I::SomeItem>, I::Error>
+    where
+        I::Error: Clone,
+    {
+        if self.peeked.is_none() {
+            let mut remaining = max_empty;
+            loop {
+                if remaining == 0 {
+                    return Ok(None);
+                }
+                match self.inner.some_next() {
+                    Nothing => remaining -= 1,
+                    result => {
+                        self.peeked = Some(result);
+                        break;
+                    }
+                }
             }
         }
+        match self.peeked.as_ref().expect("just filled above") {
+            Thing(t) => Ok(Some(t)),
+            Nothing => unreachable!("a buffered peek is never Nothing"),
+            Failure(e) => Err(e.clone()),
+        }
     }
 }
 
-impl<I, T, E> FallibleIterator for I where I: Iterator<Item = Result<T, E>> {}
+impl<I> FallibleIterator for FalliblePeekable<I>
+where
+    I: FallibleIterator,
+{
+    type SomeItem = I::SomeItem;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        match self.peeked.take() {
+            Some(result) => result,
+            None => self.inner.some_next(),
+        }
+    }
+}
+
+/// A [`FallibleIterator`] that can also be polled from the back, for
+/// bidirectional sources like ropes, deques, or byte buffers.
+pub trait DoubleEndedFallibleIterator: FallibleIterator {
+    /// Polls the next item from the back of the iterator.
+    fn some_next_back(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error>;
+
+    /// Swaps front and back, so this iterator is consumed in the opposite
+    /// direction.
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized,
+    {
+        Rev { inner: self }
+    }
+}
+
+/// An adapter around [`DoubleEndedFallibleIterator::rev`].
+#[derive(Debug)]
+pub struct Rev<I> {
+    inner: I,
+}
+
+impl<I> FallibleIterator for Rev<I>
+where
+    I: DoubleEndedFallibleIterator,
+{
+    type SomeItem = I::SomeItem;
+    type Error = I::Error;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        self.inner.some_next_back()
+    }
+}
+
+impl<I> DoubleEndedFallibleIterator for Rev<I>
+where
+    I: DoubleEndedFallibleIterator,
+{
+    fn some_next_back(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        self.inner.some_next()
+    }
+}
+
+impl<I, T, E> FallibleIterator for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type SomeItem = T;
+    type Error = E;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        match self.next() {
+            Some(Ok(t)) => Thing(t),
+            Some(Err(e)) => Failure(e),
+            None => Nothing,
+        }
+    }
+}
 
 use core::ops::{Deref, DerefMut};
 
+/// A thin wrapper that lets a [`FallibleIterator`] be turned into a plain
+/// [`Iterator`] of `Result`s via [`IntoIterator`], since the blanket
+/// [`FallibleIterator`] impl for `Iterator<Item = Result<T, E>>` would
+/// otherwise conflict with implementing [`IntoIterator`] directly on `FI`.
+#[derive(Debug)]
 pub struct W<T>(T);
 
 #[rustfmt::skip]
@@ -175,10 +756,27 @@ where
     }
 }
 
+/// The [`Iterator`] produced by [`W`]'s [`IntoIterator`] impl.
+#[derive(Debug)]
 pub struct IntoIter<FI> {
     inner: FI,
 }
 
+impl<FI> Iterator for IntoIter<W<FI>>
+where
+    FI: FallibleIterator,
+{
+    type Item = Result<FI::SomeItem, FI::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.some_next() {
+            Thing(t) => Some(Ok(t)),
+            Nothing => None,
+            Failure(e) => Some(Err(e)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -196,6 +794,151 @@ mod test {
     #[test]
     fn name() {
         let a = A;
-        for _ in a.fuse_err() {}
+        assert_eq!(a.fuse_err().take(3).count(), 3);
+    }
+
+    /// A [`FallibleIterator`] driven directly off a script of
+    /// [`crate::Fallible`] results, for exercising `Thing`/`Nothing`/
+    /// `Failure` handling without a real data source.
+    struct Seq {
+        script: alloc::vec::Vec<crate::Fallible<i32, &'static str>>,
+    }
+
+    impl Seq {
+        fn new(script: alloc::vec::Vec<crate::Fallible<i32, &'static str>>) -> Self {
+            Seq { script }
+        }
+    }
+
+    impl FallibleIterator for Seq {
+        type SomeItem = i32;
+        type Error = &'static str;
+
+        fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+            if self.script.is_empty() {
+                Nothing
+            } else {
+                self.script.remove(0)
+            }
+        }
+    }
+
+    impl DoubleEndedFallibleIterator for Seq {
+        fn some_next_back(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+            if self.script.is_empty() {
+                Nothing
+            } else {
+                self.script.pop().expect("just checked non-empty")
+            }
+        }
+    }
+
+    /// Drains a [`FallibleIterator`] into a [`Vec`] using [`fold`], which
+    /// stops cleanly the moment a [`Nothing`] is seen.
+    fn drain<I>(iter: I) -> alloc::vec::Vec<I::SomeItem>
+    where
+        I: FallibleIterator,
+        I::Error: Debug,
+    {
+        iter.fold(alloc::vec::Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn map_filter_filter_map_and_take() {
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Thing(3), Nothing]);
+        assert_eq!(drain(seq.map(|n| n * 2)), alloc::vec![2, 4, 6]);
+
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Thing(3), Nothing]);
+        assert_eq!(drain(seq.filter(|n| n % 2 == 0)), alloc::vec![2]);
+
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Thing(3), Nothing]);
+        assert_eq!(
+            drain(seq.filter_map(|n| if n % 2 == 0 { Some(n) } else { None })),
+            alloc::vec![2]
+        );
+
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Thing(3)]);
+        assert_eq!(drain(seq.take(2)), alloc::vec![1, 2]);
+    }
+
+    #[test]
+    fn count_last_nth_stop_cleanly_on_nothing() {
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Nothing, Thing(3)]);
+        assert_eq!(seq.count(), Ok(2));
+
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Nothing, Thing(3)]);
+        assert_eq!(seq.last(), Ok(Some(2)));
+
+        let mut seq = Seq::new(alloc::vec![Thing(1), Nothing, Thing(2)]);
+        assert_eq!(seq.nth(0), Ok(Some(1)));
+        assert_eq!(seq.nth(0), Ok(None));
+    }
+
+    #[test]
+    fn fold_and_for_each_stop_cleanly_on_nothing() {
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Nothing, Thing(3)]);
+        assert_eq!(seq.fold(0, |acc, item| acc + item), Ok(3));
+
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Nothing, Thing(3)]);
+        let mut seen = alloc::vec::Vec::new();
+        seq.for_each(|item| seen.push(item)).unwrap();
+        assert_eq!(seen, alloc::vec![1, 2]);
+    }
+
+    #[test]
+    fn count_stops_early_on_failure() {
+        let seq = Seq::new(alloc::vec![Thing(1), Failure("boom"), Thing(2)]);
+        assert_eq!(seq.count(), Err("boom"));
+    }
+
+    #[test]
+    fn seek_next_within_stops_after_max_empty() {
+        let mut seq = Seq::new(alloc::vec![Nothing, Nothing, Nothing, Thing(1)]);
+        assert_eq!(seq.seek_next_within(2), None);
+
+        let mut seq = Seq::new(alloc::vec![Nothing, Thing(1)]);
+        assert_eq!(seq.seek_next_within(2), Some(1));
+    }
+
+    #[test]
+    fn with_patience_bounds_consecutive_skips() {
+        let seq = Seq::new(alloc::vec![Nothing, Nothing, Nothing, Thing(1)]);
+        let collected: alloc::vec::Vec<_> = seq.fuse_err().with_patience(2).collect();
+        assert_eq!(collected, alloc::vec::Vec::<i32>::new());
+
+        let seq = Seq::new(alloc::vec![Nothing, Thing(1)]);
+        let collected: alloc::vec::Vec<_> = seq.fuse_err().with_patience(2).collect();
+        assert_eq!(collected, alloc::vec![1]);
+    }
+
+    #[test]
+    fn peekable_buffers_a_lookahead() {
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2)]);
+        let mut peekable = seq.peekable();
+        assert_eq!(peekable.peek(), Ok(Some(&1)));
+        assert_eq!(peekable.peek(), Ok(Some(&1)));
+        assert_eq!(peekable.some_next(), Thing(1));
+        assert_eq!(peekable.some_next(), Thing(2));
+    }
+
+    #[test]
+    fn peek_within_bounds_consecutive_nothings() {
+        let seq = Seq::new(alloc::vec![Nothing, Nothing, Nothing, Thing(1)]);
+        let mut peekable = seq.peekable();
+        assert_eq!(peekable.peek_within(2), Ok(None));
+
+        let seq = Seq::new(alloc::vec![Nothing, Thing(1)]);
+        let mut peekable = seq.peekable();
+        assert_eq!(peekable.peek_within(2), Ok(Some(&1)));
+    }
+
+    #[test]
+    fn rev_swaps_front_and_back() {
+        let seq = Seq::new(alloc::vec![Thing(1), Thing(2), Thing(3)]);
+        assert_eq!(drain(seq.rev()), alloc::vec![3, 2, 1]);
     }
 }
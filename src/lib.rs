@@ -70,8 +70,10 @@
 // #![feature(never_type)]
 // #![forbid(unsafe_code)]
 
+extern crate alloc;
+
 /// An iterator over [`Fallible`]s.
-// pub mod iter;
+pub mod iter;
 /// A collection of useful imports
 pub mod prelude;
 
@@ -107,15 +109,94 @@ pub mod some;
 //     }
 // }
 
-// impl<T, E> Fallible<T, E> {
-//     pub fn unwrap_or_default(self) -> T
-//     where
-//         T: Default,
-//     {
-//         match self {
-//             Thing(t) => t,
-//             Failure(_) => T::default(),
-//             Nothing => T::default(),
-//         }
-//     }
-// }
+/// The canonical return type of [`iter::FallibleIterator::some_next`]. A
+/// [`Thing`] is a real value, [`Nothing`] is insignifigent and just means
+/// poll again, and [`Failure`] is a real error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fallible<T, E> {
+    /// A value was produced.
+    Thing(T),
+    /// Nothing was produced this poll. Not signifigent, keep polling.
+    Nothing,
+    /// A real error surfaced.
+    Failure(E),
+}
+
+pub use Fallible::{Failure, Nothing, Thing};
+
+impl<T, E> Fallible<T, E> {
+    /// Returns the contained value, or `T::default()` for both [`Nothing`]
+    /// and [`Failure`].
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Thing(t) => t,
+            Failure(_) => T::default(),
+            Nothing => T::default(),
+        }
+    }
+
+    /// Converts to an [`Option`] of the value, discarding any error.
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Thing(t) => Some(t),
+            Nothing | Failure(_) => None,
+        }
+    }
+
+    /// Converts to an [`Option`] of the error, discarding any value.
+    pub fn err(self) -> Option<E> {
+        match self {
+            Failure(e) => Some(e),
+            Thing(_) | Nothing => None,
+        }
+    }
+
+    /// Returns the contained value, if there is one. Reads better than
+    /// [`Fallible::ok`] when the error doesn't matter at the call site.
+    pub fn thing(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+impl<T, E> From<Fallible<T, E>> for Result<Option<T>, E> {
+    fn from(value: Fallible<T, E>) -> Self {
+        match value {
+            Thing(t) => Ok(Some(t)),
+            Nothing => Ok(None),
+            Failure(e) => Err(e),
+        }
+    }
+}
+
+impl<T, E> From<Result<Option<T>, E>> for Fallible<T, E> {
+    fn from(value: Result<Option<T>, E>) -> Self {
+        match value {
+            Ok(Some(t)) => Thing(t),
+            Ok(None) => Nothing,
+            Err(e) => Failure(e),
+        }
+    }
+}
+
+impl<T, E> From<Fallible<T, E>> for Option<Result<T, E>> {
+    fn from(value: Fallible<T, E>) -> Self {
+        match value {
+            Thing(t) => Some(Ok(t)),
+            Nothing => None,
+            Failure(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<T, E> From<Option<Result<T, E>>> for Fallible<T, E> {
+    fn from(value: Option<Result<T, E>>) -> Self {
+        match value {
+            Some(Ok(t)) => Thing(t),
+            Some(Err(e)) => Failure(e),
+            None => Nothing,
+        }
+    }
+}
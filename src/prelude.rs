@@ -0,0 +1,5 @@
+//! Everything you need to get going, re-exported in one place.
+
+pub use crate::iter::{DoubleEndedFallibleIterator, FallibleIterator};
+pub use crate::some::streaming::FallibleStreamingIterator;
+pub use crate::{Fallible, Failure, Nothing, Thing};
@@ -1,3 +1,6 @@
+/// Fallible iteration over borrowed/lending items.
+pub mod streaming;
+
 /// Like the [`Into`] and [`TryInto`] trait but is failable with no given reason. The reason to use
 /// this would be when a conversion is not possible but the program should not stop beacuse of it.
 pub trait SomeInto<T> {
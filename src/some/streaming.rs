@@ -0,0 +1,217 @@
+use crate::iter::FallibleIterator;
+
+/// An iterator whose items borrow from the iterator itself, for sources like
+/// parsers, DB cursors or decompression buffers that can't hand an owned item
+/// out of `next`. Ordinary [`Iterator`] can't express this because its
+/// `next` can't return something borrowed from `&mut self`.
+pub trait FallibleStreamingIterator {
+    /// The type of the borrowed item.
+    type Item;
+    /// The error this iterator can fail with.
+    type Error;
+
+    /// Advances the iterator to the next position.
+    fn advance(&mut self) -> Result<(), Self::Error>;
+
+    /// Borrows the item at the current position, if there is one.
+    fn get(&self) -> Option<&Self::Item>;
+
+    /// Advances then borrows the new current item. Lets callers write
+    /// `while let Some(v) = it.next()? { ... }`.
+    fn next(&mut self) -> Result<Option<&Self::Item>, Self::Error> {
+        self.advance()?;
+        Ok(self.get())
+    }
+
+    /// A hint for how many items remain, same contract as
+    /// [`Iterator::size_hint`].
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Folds every item into an accumulator, stopping early the moment
+    /// `advance` returns an error.
+    fn fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, &Self::Item) -> B,
+    {
+        let mut acc = init;
+        while let Some(item) = self.next()? {
+            acc = f(acc, item);
+        }
+        Ok(acc)
+    }
+
+    /// Lazily maps every borrowed item through `f`, keeping the
+    /// streaming/borrowing contract.
+    fn map_ref<B, F>(self, f: F) -> MapRef<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> &B,
+    {
+        MapRef { inner: self, f }
+    }
+
+    /// Turns this streaming iterator into an owning [`FallibleIterator`] by
+    /// cloning each item out as it's visited. A true end of stream (`Ok(None)`
+    /// from [`next`](Self::next)) maps to [`Failure`](crate::Failure)`(None)`
+    /// rather than [`Nothing`](crate::Nothing), so callers can still drain it
+    /// with unbounded combinators like
+    /// [`fuse_err`](crate::iter::FallibleIterator::fuse_err) without hanging;
+    /// a real underlying error surfaces as `Failure(Some(e))`.
+    fn owned(self) -> Owned<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Owned { inner: self }
+    }
+}
+
+/// A lazy adapter around [`FallibleStreamingIterator::map_ref`].
+#[derive(Debug)]
+pub struct MapRef<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F, B> FallibleStreamingIterator for MapRef<I, F>
+where
+    I: FallibleStreamingIterator,
+    F: Fn(&I::Item) -> &B,
+{
+    type Item = B;
+    type Error = I::Error;
+
+    fn advance(&mut self) -> Result<(), Self::Error> {
+        self.inner.advance()
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.inner.get().map(&self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A bridging adapter around [`FallibleStreamingIterator::owned`].
+#[derive(Debug)]
+pub struct Owned<I> {
+    inner: I,
+}
+
+impl<I> FallibleIterator for Owned<I>
+where
+    I: FallibleStreamingIterator,
+    I::Item: Clone,
+{
+    type SomeItem = I::Item;
+    /// `None` means the streaming source genuinely ran out; `Some(e)` is a
+    /// real underlying error. See [`owned`](FallibleStreamingIterator::owned).
+    type Error = Option<I::Error>;
+
+    fn some_next(&mut self) -> crate::Fallible<Self::SomeItem, Self::Error> {
+        match self.inner.next() {
+            Ok(Some(item)) => crate::Thing(item.clone()),
+            Ok(None) => crate::Failure(None),
+            Err(e) => crate::Failure(Some(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Walks a fixed buffer one position at a time, borrowing the current
+    /// item from `self` instead of handing out an owned value.
+    struct Cursor {
+        items: alloc::vec::Vec<i32>,
+        pos: usize,
+    }
+
+    impl FallibleStreamingIterator for Cursor {
+        type Item = i32;
+        type Error = &'static str;
+
+        fn advance(&mut self) -> Result<(), Self::Error> {
+            if self.pos >= self.items.len() {
+                return Err("out of items");
+            }
+            self.pos += 1;
+            Ok(())
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            self.items.get(self.pos.wrapping_sub(1))
+        }
+    }
+
+    #[test]
+    fn next_advances_then_borrows() {
+        let mut cursor = Cursor {
+            items: alloc::vec![1, 2],
+            pos: 0,
+        };
+        assert_eq!(cursor.next(), Ok(Some(&1)));
+        assert_eq!(cursor.next(), Ok(Some(&2)));
+        assert_eq!(cursor.next(), Err("out of items"));
+    }
+
+    #[test]
+    fn map_ref_transforms_the_borrow() {
+        let cursor = Cursor {
+            items: alloc::vec![1, 2],
+            pos: 0,
+        };
+        let mut mapped = cursor.map_ref(|n| n);
+        assert_eq!(mapped.next(), Ok(Some(&1)));
+    }
+
+    #[test]
+    fn owned_bridges_to_fallible_iterator() {
+        let cursor = Cursor {
+            items: alloc::vec![1, 2],
+            pos: 0,
+        };
+        let collected: alloc::vec::Vec<i32> = cursor.owned().fuse_err().collect();
+        assert_eq!(collected, alloc::vec![1, 2]);
+    }
+
+    /// Like [`Cursor`] but signals exhaustion the well-behaved way: `advance`
+    /// always succeeds and `get` just naturally starts returning `None`,
+    /// rather than `advance` erroring out once the buffer is drained.
+    struct ExhaustingCursor {
+        items: alloc::vec::Vec<i32>,
+        pos: usize,
+    }
+
+    impl FallibleStreamingIterator for ExhaustingCursor {
+        type Item = i32;
+        type Error = &'static str;
+
+        fn advance(&mut self) -> Result<(), Self::Error> {
+            if self.pos <= self.items.len() {
+                self.pos += 1;
+            }
+            Ok(())
+        }
+
+        fn get(&self) -> Option<&Self::Item> {
+            self.pos.checked_sub(1).and_then(|i| self.items.get(i))
+        }
+    }
+
+    #[test]
+    fn owned_terminates_on_ok_none_end_of_stream() {
+        let cursor = ExhaustingCursor {
+            items: alloc::vec![1, 2],
+            pos: 0,
+        };
+        let collected: alloc::vec::Vec<i32> = cursor.owned().fuse_err().collect();
+        assert_eq!(collected, alloc::vec![1, 2]);
+    }
+}